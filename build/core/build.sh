@@ -2,7 +2,7 @@
 
 set -e
 
-readonly PENV_VERSION="2.2"
+readonly PENV_VERSION="2.3"
 readonly PENV_BUILD_TIMESTAMP=$(date -u +"%Y-%m-%dT%H:%M:%SZ")
 
 export PENV_ENV_MODE="build"
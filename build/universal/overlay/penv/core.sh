@@ -5,8 +5,12 @@ export PENV_ENV_DISTRO=${PENV_ENV_DISTRO:-unknown}
 export PENV_BUILD_STAGE=${PENV_BUILD_STAGE:-unknown}
 export PENV_CONFIG_VERBOSE=${PENV_CONFIG_VERBOSE:-0}
 
-# Set up runtime signal directory
-export PENV_SIGNAL=${PENV_SIGNAL:-"/tmp/penv/signals"}
+# Set up runtime signal directory (per uid, so penv shell -u users don't collide)
+if [ "$(id -u)" = "0" ]; then
+    export PENV_SIGNAL=${PENV_SIGNAL:-"/tmp/penv/signals"}
+else
+    export PENV_SIGNAL=${PENV_SIGNAL:-"/tmp/penv-$(id -u)/signals"}
+fi
 rm -rf "$PENV_SIGNAL"
 mkdir -p "$PENV_SIGNAL"
 
@@ -30,9 +34,9 @@ if [ -z "$PENV_CORE_LOADED" ]; then
     fi
 fi
 
-# Set standard environment
-export HOME="/root"
-export USER="root"
+# Set standard environment (penv shell -u passes the user's HOME and USER)
+export HOME="${PENV_ENV_HOME:-/root}"
+export USER="${PENV_ENV_USER:-root}"
 export PATH="/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin"
 
 export SYSTEMD_OFFLINE=1
@@ -28,14 +28,17 @@ cleanup() {
 
 trap cleanup EXIT INT TERM
 
-# Unset host environment variables (keep PENV_* and safe variables)
+# Unset host environment variables (keep PENV_*, safe variables and PENV_KEEP_ENV)
 for var in $(env | cut -d= -f1); do
     case "$var" in
         PENV_*|HOME|USER|SHELL|TERM|COLORTERM|LANG|LC_ALL|LC_CTYPE|DISPLAY|PATH|PWD|OLDPWD|SHLVL|_)
             continue ;;
-        *)
-            unset "$var" ;;
     esac
+    case " $PENV_KEEP_ENV " in
+        *" $var "*)
+            continue ;;
+    esac
+    unset "$var"
 done
 
 # Check for prepare signal
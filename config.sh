@@ -1,2 +1,2 @@
-export PENV_CONFIG_VERBOSE=0
-export PENV_CONFIG_MNT_HOME=0
+export PENV_CONFIG_VERBOSE=${PENV_CONFIG_VERBOSE:-0}
+export PENV_CONFIG_MNT_HOME=${PENV_CONFIG_MNT_HOME:-0}
@@ -42,11 +42,12 @@ cmd::init(){
 cmd::import(){
   if [[ $# -lt 2 ]]; then
     err "Usage: penv import <distro-id> <tarball-path>"
-    echo -e "       penv import <distro-id> <tarball-path> [-f <family>]"
+    echo -e "       penv import <distro-id> <tarball-path> [-f <family>] [-s <sha256>]"
     info "Import a custom rootfs tarball as a distro"
     echo -e "  ${C_DIM}<distro-id>${C_RESET}     Unique identifier for the imported distro"
     echo -e "  ${C_DIM}<tarball-path>${C_RESET} Path to the rootfs tarball (.tar.gz)"
     echo -e "  ${C_DIM}-f <family>${C_RESET}    Optional: distro family (debian, alpine, arch, etc.)"
+    echo -e "  ${C_DIM}-s <sha256>${C_RESET}    Optional: expected SHA256 of the tarball"
     return 2
   fi
   
@@ -55,6 +56,7 @@ cmd::import(){
   shift 2
   
   local family=""
+  local sha256=""
   
   # Parse optional arguments
   while [[ $# -gt 0 ]]; do
@@ -67,6 +69,14 @@ cmd::import(){
         family="$2"
         shift 2
         ;;
+      -s|--sha256)
+        if [[ -z "${2:-}" ]]; then
+          err "Option -s requires a value"
+          return 2
+        fi
+        sha256="$2"
+        shift 2
+        ;;
       *)
         err "Unknown option: $1"
         return 2
@@ -74,7 +84,7 @@ cmd::import(){
     esac
   done
   
-  distro::import "$distro_id" "$tarball_path" "$family"
+  distro::import "$distro_id" "$tarball_path" "$family" "$sha256"
 }
 
 cmd::mod(){
@@ -222,6 +232,76 @@ cmd::create(){
 }
 
 cmd::shell(){
+  local workdir_set=false
+  
+  # Parse options up to the environment name; the rest is the command
+  while [[ $# -gt 0 ]]; do
+    case "$1" in
+      -w|--workdir)
+        if [[ -z "${2:-}" ]]; then
+          err "Option -w requires a value"
+          return 2
+        fi
+        PENV_WORKDIR="$2"
+        workdir_set=true
+        shift 2
+        ;;
+      -u|--user)
+        if [[ -z "${2:-}" ]]; then
+          err "Option -u requires a value"
+          return 2
+        fi
+        PENV_USER="$2"
+        shift 2
+        ;;
+      -b|--bind)
+        if [[ -z "${2:-}" ]]; then
+          err "Option -b requires a value"
+          return 2
+        fi
+        PENV_BINDS+=("$2")
+        shift 2
+        ;;
+      --bind-ro)
+        if [[ -z "${2:-}" ]]; then
+          err "Option --bind-ro requires a value"
+          return 2
+        fi
+        PENV_BINDS_RO+=("$2")
+        shift 2
+        ;;
+      -k|--keep-env)
+        if [[ -z "${2:-}" ]]; then
+          err "Option -k requires a value"
+          return 2
+        fi
+        PENV_KEEP_VARS+=("$2")
+        shift 2
+        ;;
+      --cwd-host)
+        PENV_CWD_HOST="/workspace"
+        shift
+        ;;
+      --cwd-host=*)
+        PENV_CWD_HOST="${1#*=}"
+        shift
+        ;;
+      -*)
+        err "Unknown option: $1"
+        info "Usage: penv shell [-w <dir>] [-u <uid[:gid]>] [-b|--bind-ro <src:dest> ...] [-k <var> ...] [--cwd-host[=<dir>]] <name> [command...]"
+        return 2
+        ;;
+      *)
+        break
+        ;;
+    esac
+  done
+  
+  # Start in the mounted host directory unless -w says otherwise
+  if [[ -n "$PENV_CWD_HOST" ]] && ! $workdir_set; then
+    PENV_WORKDIR="$PENV_CWD_HOST"
+  fi
+  
   env::shell "$@"
 }
 
@@ -357,6 +437,7 @@ cmd::usage(){
   echo -e "  ${C_GREEN}penv import${C_RESET} ${C_YELLOW}<id>${C_RESET} ${C_YELLOW}<tarball>${C_RESET}        Import custom rootfs tarball"
   echo -e "    ${C_DIM}Options:${C_RESET}"
   echo -e "      ${C_DIM}-f, --family <name>${C_RESET}         Set distro family (debian, alpine, etc.)"
+  echo -e "      ${C_DIM}-s, --sha256 <digest>${C_RESET}       Verify tarball checksum before import"
   echo -e "  ${C_GREEN}penv mod${C_RESET} ${C_YELLOW}<distro-id>${C_RESET}              Modify distro with addons/shell"
   echo -e "    ${C_DIM}Options:${C_RESET}"
   echo -e "      ${C_DIM}-a, --addon <id>${C_RESET}            Apply addon (can use multiple times)"
@@ -366,6 +447,13 @@ cmd::usage(){
   echo -e "    ${C_DIM}Options:${C_RESET}"
   echo -e "      ${C_DIM}-l, --list${C_RESET}                  List installed distros"
   echo -e "  ${C_GREEN}penv shell${C_RESET} ${C_YELLOW}<name>${C_RESET} ${C_YELLOW}[cmd]${C_RESET}           Enter environment shell or run command"
+  echo -e "    ${C_DIM}Options:${C_RESET}"
+  echo -e "      ${C_DIM}-w, --workdir <dir>${C_RESET}         Start in <dir> inside the environment ${C_DIM}(default: /)${C_RESET}"
+  echo -e "      ${C_DIM}-u, --user <uid[:gid]>${C_RESET}      Run as numeric uid/gid instead of root"
+  echo -e "      ${C_DIM}-b, --bind <src:dest>${C_RESET}       Bind host <src> at <dest> (can use multiple times)"
+  echo -e "      ${C_DIM}--bind-ro <src:dest>${C_RESET}        Same, but read-only ${C_DIM}(root only)${C_RESET}"
+  echo -e "      ${C_DIM}-k, --keep-env <var>${C_RESET}        Keep host variable <var> (can use multiple times)"
+  echo -e "      ${C_DIM}--cwd-host[=<dir>]${C_RESET}          Bind current directory at <dir> and start there ${C_DIM}(default: /workspace)${C_RESET}"
   echo -e "  ${C_GREEN}penv rm${C_RESET} ${C_YELLOW}<name>${C_RESET}                     Remove environment"
   echo -e "  ${C_GREEN}penv rm -d${C_RESET} ${C_YELLOW}<id>${C_RESET}                   Remove distro"
   echo -e "  ${C_GREEN}penv rm -a${C_RESET}                        Remove all environments"
@@ -398,6 +486,11 @@ cmd::usage(){
   echo "  penv new myenv ubuntu-24.04"
   echo "  penv shell myenv"
   echo "  penv shell myenv python3 --version"
+  echo "  penv shell -w /root myenv ls"
+  echo "  penv shell -u 1000:1000 myenv id"
+  echo "  penv shell -b ~/project:/src myenv"
+  echo "  penv shell --cwd-host myenv make"
+  echo "  penv shell -k SSH_AUTH_SOCK myenv"
   echo
   echo "  # Remove resources"
   echo "  penv rm myenv            # remove environment"
@@ -410,5 +503,7 @@ cmd::usage(){
   echo "  penv list -o -a          # online distros and addons"
   echo
   echo -e "${C_BOLD}ENVIRONMENT:${C_RESET}"
-  echo -e "  ${C_DIM}PENV_INDEX_URL${C_RESET}  Custom index URL (default: GitHub)"
+  echo -e "  ${C_DIM}PENV_INDEX_URL${C_RESET}        Custom index URL (default: GitHub)"
+  echo -e "  ${C_DIM}PENV_CONFIG_VERBOSE${C_RESET}   Verbose environment scripts (0/1, overrides config.sh)"
+  echo -e "  ${C_DIM}PENV_CONFIG_MNT_HOME${C_RESET}  Mount host \$HOME at /mnt (0/1, overrides config.sh)"
 }
@@ -8,6 +8,14 @@ ENVS_DIR="${PENV_DIR}/envs"
 BIN_DIR="${PENV_DIR}/bin"
 LOCAL_INDEX="${PENV_DIR}/local_index.json"
 
+# -------- Runtime options (set by commands) --------
+PENV_WORKDIR="/"                # Working directory inside the environment
+PENV_USER=""                    # uid:gid to run as (empty: fake root)
+PENV_BINDS=()                   # Extra bind mounts (SRC:DEST)
+PENV_BINDS_RO=()                # Extra read-only bind mounts (SRC:DEST)
+PENV_CWD_HOST=""                # Mountpoint for the host cwd (empty: off)
+PENV_KEEP_VARS=()               # Host variables kept inside the environment
+
 # Remote index URL - change this to use a different index
 INDEX_URL="${PENV_INDEX_URL:-https://raw.githubusercontent.com/Aeliux/penv/master/index.json}"
 
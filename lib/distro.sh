@@ -8,6 +8,7 @@ distro::import(){
   local distro_id="$1"
   local tarball_path="$2"
   local family="${3:-}"
+  local sha256="${4:-}"
   
   ensure_dirs
   require_jq
@@ -40,6 +41,23 @@ distro::import(){
     return 1
   fi
   
+  # Verify checksum if one was given
+  if [[ -n "$sha256" ]]; then
+    if [[ ! "$sha256" =~ ^[0-9a-fA-F]{64}$ ]]; then
+      err "Invalid SHA256 checksum: $sha256"
+      return 1
+    fi
+    # An explicit -s must not silently import an unverified tarball
+    if ! command -v sha256sum >/dev/null 2>&1; then
+      err "sha256sum is required to verify the tarball checksum"
+      return 1
+    fi
+    if ! verify_checksum "$tarball_path" "${sha256,,}"; then
+      err "Tarball failed checksum verification"
+      return 1
+    fi
+  fi
+  
   # Validate tarball is actually a tar/gzip file
   local file_type
   file_type=$(file -b "$tarball_path" 2>/dev/null || echo "")
@@ -84,11 +84,11 @@ env::create(){
 
 # Enter environment shell
 env::shell(){
-  local env_name="$1"
-  shift
+  local env_name="${1:-}"
+  shift || true
   
   if [[ -z "$env_name" ]]; then
-    err "Usage: penv shell <name> [command...]"
+    err "Usage: penv shell [-w <dir>] [-u <uid[:gid]>] [-b|--bind-ro <src:dest> ...] [-k <var> ...] [--cwd-host[=<dir>]] <name> [command...]"
     return 2
   fi
   
@@ -104,14 +104,191 @@ env::shell(){
     return 2
   fi
   
+  # Bind the host cwd, unless it already lives inside the environment.
+  # It goes first, so binds given with -b under it are mounted on top.
+  if [[ -n "$PENV_CWD_HOST" ]]; then
+    if [[ "$PENV_CWD_HOST" != /* ]]; then
+      err "Host cwd mountpoint must be an absolute path: $PENV_CWD_HOST"
+      return 2
+    fi
+    local cwd real_root
+    cwd="$(pwd -P)"
+    if [[ "$cwd" == *:* ]]; then
+      err "Current directory contains ':' and cannot be bind mounted: $cwd"
+      return 2
+    fi
+    real_root="$(realpath "$env_root")"
+    if [[ "$cwd" == "$real_root" || "$cwd" == "$real_root"/* ]]; then
+      if [[ "$PENV_WORKDIR" == "$PENV_CWD_HOST" ]]; then
+        PENV_WORKDIR="${cwd#"$real_root"}"
+        PENV_WORKDIR="${PENV_WORKDIR:-/}"
+      fi
+    else
+      PENV_BINDS=("$cwd:$PENV_CWD_HOST" "${PENV_BINDS[@]}")
+    fi
+  fi
+  
+  # Validate bind mounts and resolve sources to absolute host paths
+  env::resolve_binds PENV_BINDS "$env_root" || return 2
+  env::resolve_binds PENV_BINDS_RO "$env_root" || return 2
+  if [[ ${#PENV_BINDS_RO[@]} -gt 0 ]] && [[ $EUID -ne 0 ]]; then
+    err "Read-only bind mounts require root (proot cannot enforce them)"
+    info "Use ${C_BOLD}-b${C_RESET} for a writable bind instead"
+    return 2
+  fi
+  
+  # Validate working directory
+  if [[ "$PENV_WORKDIR" != /* ]]; then
+    err "Working directory must be an absolute path: $PENV_WORKDIR"
+    return 2
+  fi
+  if ! env::is_bind_path "$PENV_WORKDIR" && [[ ! -d "$env_root$PENV_WORKDIR" ]]; then
+    err "Working directory not found in environment: $PENV_WORKDIR"
+    return 1
+  fi
+  
+  # Validate user spec (numeric only, gid defaults to uid)
+  if [[ -n "$PENV_USER" ]]; then
+    if [[ ! "$PENV_USER" =~ ^[0-9]+(:[0-9]+)?$ ]]; then
+      err "Invalid user (expected numeric uid[:gid]): $PENV_USER"
+      return 2
+    fi
+    if [[ "$PENV_USER" != *:* ]]; then
+      PENV_USER="$PENV_USER:$PENV_USER"
+    fi
+  fi
+  
+  # Pick HOME and USER for the requested uid from the environment's passwd
+  local user_name="root" user_home="/root"
+  if [[ -n "$PENV_USER" ]]; then
+    local entry
+    entry=$(awk -F: -v uid="${PENV_USER%%:*}" '$3 == uid { print $1 ":" $6; exit }' "$env_root/etc/passwd" 2>/dev/null || true)
+    if [[ -n "$entry" ]]; then
+      user_name="${entry%%:*}"
+      user_home="${entry#*:}"
+    else
+      user_name="${PENV_USER%%:*}"
+      user_home="/"
+    fi
+    
+    # Create a missing home here, the user may not be allowed to inside
+    if [[ "$user_home" == /* ]] && [[ ! -d "$env_root$user_home" ]]; then
+      mkdir -p "$env_root$user_home"
+      [[ -d "$env_root/etc/skel" ]] && cp -a "$env_root/etc/skel/." "$env_root$user_home"/
+      if [[ $EUID -eq 0 ]]; then
+        chown -R "$PENV_USER" "$env_root$user_home"
+      fi
+      chmod 700 "$env_root$user_home"
+    fi
+    
+    # core.sh only honours PENV_ENV_HOME/PENV_ENV_USER since 2.3
+    if ! requires_version "$env_root" "2.3"; then
+      warn "Environment is older than penv 2.3, HOME and USER stay /root and root" >&2
+    fi
+  fi
+  
+  # Validate variables to keep across the startup environment cleanup
+  local var
+  for var in "${PENV_KEEP_VARS[@]}"; do
+    if [[ ! "$var" =~ ^[A-Za-z_][A-Za-z0-9_]*$ ]]; then
+      err "Invalid variable name: $var"
+      return 2
+    fi
+  done
+  # The allowlist is read by /penv/startup.sh, which gained it in 2.3
+  if [[ ${#PENV_KEEP_VARS[@]} -gt 0 ]] && ! requires_version "$env_root" "2.3"; then
+    err "Option -k needs an environment built for penv 2.3+ (found: $(get_penv_version "$env_root"))"
+    info "Recreate the environment from an updated distro to use it"
+    return 2
+  fi
+  
+  # Catch typos before startup scripts run (bind mounts are not visible yet)
+  if [[ $# -gt 0 ]]; then
+    if [[ "$1" == /* ]]; then
+      if ! env::is_bind_path "$1" && [[ ! -e "$env_root$1" ]] && [[ ! -L "$env_root$1" ]]; then
+        err "Command not found in environment: $1"
+        return 1
+      fi
+    elif [[ "$1" != */* ]]; then
+      local dir found=false
+      for dir in /usr/local/sbin /usr/local/bin /usr/sbin /usr/bin /sbin /bin; do
+        if [[ -e "$env_root$dir/$1" ]] || [[ -L "$env_root$dir/$1" ]]; then
+          found=true
+          break
+        fi
+      done
+      if ! $found; then
+        warn "Command not found in environment PATH: $1" >&2
+      fi
+    fi
+  fi
+  
   require_proot
 
   export PENV_ENV_MODE="environment"
   export PENV_ENV_NAME="$env_name"
+  export PENV_ENV_USER="$user_name"
+  export PENV_ENV_HOME="$user_home"
+  export PENV_KEEP_ENV="$(IFS=' '; echo "${PENV_KEEP_VARS[*]}")"
   
   exec_in_proot "$env_root" "$@"
 }
 
+# Validate SRC:DEST bind specs in the named array, resolving both in place
+# (DEST is confined to the environment rooted at $2)
+env::resolve_binds(){
+  local -n binds="$1"
+  local env_root="$2"
+  local i spec src dest real_root resolved
+  
+  real_root="$(realpath "$env_root")"
+  
+  for i in "${!binds[@]}"; do
+    spec="${binds[$i]}"
+    if [[ "$spec" != *:* ]]; then
+      err "Invalid bind mount (expected SRC:DEST): $spec"
+      return 1
+    fi
+    src="${spec%%:*}"
+    dest="${spec#*:}"
+    
+    if [[ -z "$src" ]] || [[ "$dest" != /* ]]; then
+      err "Invalid bind mount (DEST must be an absolute path): $spec"
+      return 1
+    fi
+    if [[ "/$dest/" == *"/../"* ]] || [[ "$dest" =~ ^/+$ ]]; then
+      err "Invalid bind mount (DEST must not be / or contain '..'): $spec"
+      return 1
+    fi
+    if [[ ! -e "$src" ]]; then
+      err "Bind mount source not found: $src"
+      return 1
+    fi
+    
+    # Resolve symlinks so DEST cannot point back out to the host
+    resolved="$(realpath -m "$real_root$dest")"
+    if [[ "$resolved" != "$real_root"/* ]]; then
+      err "Invalid bind mount (DEST resolves outside the environment): $spec"
+      return 1
+    fi
+    
+    binds[$i]="$(realpath "$src"):${resolved#"$real_root"}"
+  done
+}
+
+# Check whether a path inside the environment is provided by a bind mount
+env::is_bind_path(){
+  local path="$1" bind dest
+  
+  for bind in "${PENV_BINDS[@]}" "${PENV_BINDS_RO[@]}"; do
+    dest="${bind#*:}"
+    if [[ "$path" == "$dest" || "$path" == "$dest"/* ]]; then
+      return 0
+    fi
+  done
+  return 1
+}
+
 # Delete environment
 env::delete(){
   local env_name="$1"
@@ -488,33 +488,138 @@ exec_in_proot(){
       mount --bind "$HOME" "$rootfs/mnt" || true
     fi
     
+    # Mount user-requested binds, parents before the binds nested in them,
+    # so a nested destination is resolved and created in the mounted view
+    # rather than in the rootfs hidden underneath. Read-only binds are
+    # remounted straight away, so nothing is ever created inside them.
+    # Any failure aborts the run rather than entering with missing binds.
+    # Mountpoints penv creates are recorded, with the topmost directory it
+    # had to create, so they can be removed again afterwards.
+    local real_root bind src dest top depth ro i
+    local binds=() binds_ro=() mounted=() created=() created_top=()
+    local mount_failed=false
+    real_root="$(realpath "$rootfs")"
+    while IFS=$'\t' read -r depth ro bind; do
+      binds+=("$bind")
+      binds_ro+=("$ro")
+    done < <(
+      {
+        for bind in "${PENV_BINDS[@]}"; do
+          depth="${bind#*:}"
+          depth="${depth//[^\/]/}"
+          printf '%d\t0\t%s\n' "${#depth}" "$bind"
+        done
+        for bind in "${PENV_BINDS_RO[@]}"; do
+          depth="${bind#*:}"
+          depth="${depth//[^\/]/}"
+          printf '%d\t1\t%s\n' "${#depth}" "$bind"
+        done
+      } | sort -s -n -k1,1
+    )
+    for i in "${!binds[@]}"; do
+      bind="${binds[$i]}"
+      src="${bind%%:*}"
+      # Resolve again now that any parent bind is mounted over the rootfs
+      dest="$(realpath -m "$real_root${bind#*:}")"
+      if [[ "$dest" != "$real_root"/* ]]; then
+        err "Invalid bind mount (DEST resolves outside the environment): $bind"
+        mount_failed=true
+        break
+      fi
+      if [[ ! -e "$dest" ]]; then
+        top="$dest"
+        while [[ ! -e "$(dirname "$top")" ]]; do
+          top="$(dirname "$top")"
+        done
+        created[$i]="$dest"
+        created_top[$i]="$top"
+      fi
+      # Destination must match the source type: directory or empty file
+      if [[ -d "$src" ]]; then
+        mkdir -p "$dest" || mount_failed=true
+      elif [[ ! -e "$dest" ]]; then
+        { mkdir -p "$(dirname "$dest")" && touch "$dest"; } || mount_failed=true
+      fi
+      if $mount_failed || ! mount --bind "$src" "$dest"; then
+        err "Failed to bind mount: $bind"
+        mount_failed=true
+        break
+      fi
+      mounted[$i]="$dest"
+      if [[ "${binds_ro[$i]}" -eq 1 ]] && ! mount -o remount,bind,ro "$dest"; then
+        err "Failed to make bind mount read-only: $bind"
+        mount_failed=true
+        break
+      fi
+    done
+
+    local chroot_args=()
+    if [[ -n "$PENV_USER" ]]; then
+      chroot_args+=(--userspec="$PENV_USER")
+    fi
+    
     export PENV_ENV_PARENT="chroot"
-    # Execute in chroot
-    chroot "$rootfs" "${cmd[@]}"
-    local exit_code=$?
+    # Execute in chroot (chroot always starts in /, so cd first if needed)
+    local exit_code=0
+    if $mount_failed; then
+      exit_code=1
+    elif [[ "$PENV_WORKDIR" != "/" ]]; then
+      chroot "${chroot_args[@]}" "$rootfs" /bin/sh -c 'cd "$1" && shift && exec "$@"' sh "$PENV_WORKDIR" "${cmd[@]}" || exit_code=$?
+    else
+      chroot "${chroot_args[@]}" "$rootfs" "${cmd[@]}" || exit_code=$?
+    fi
     
-    # Cleanup mounts
+    # Cleanup binds in reverse order. Each created mountpoint is removed,
+    # walking up to the topmost created directory, while the bind it was
+    # created in is still mounted.
+    local path
+    for ((i = ${#binds[@]} - 1; i >= 0; i--)); do
+      if [[ -n "${mounted[$i]:-}" ]]; then
+        umount -l "${mounted[$i]}" || true
+      fi
+      path="${created[$i]:-}"
+      while [[ -n "$path" ]]; do
+        if [[ -d "$path" ]]; then
+          rmdir "$path" 2>/dev/null || break
+        elif [[ -f "$path" ]] && [[ ! -s "$path" ]]; then
+          rm -f "$path"
+        else
+          break
+        fi
+        [[ "$path" == "${created_top[$i]}" ]] && break
+        path="$(dirname "$path")"
+      done
+    done
     umount -l "$rootfs/dev/pts" || true
     umount -l "$rootfs/dev/shm" || true
     umount -l "$rootfs/dev" || true
     umount -l "$rootfs/proc" || true
     umount -l "$rootfs/sys" || true
     umount -l "$rootfs/mnt" 2>/dev/null || true
-    
+
     return $exit_code
   else
     # Use proot for non-root users
     require_proot
     
-    local proot_args=(
-      -0                        # Fake root user
+    local proot_args=()
+    if [[ -n "$PENV_USER" ]]; then
+      proot_args+=(-i "$PENV_USER")   # Fake the requested uid:gid
+    else
+      proot_args+=(-0)                # Fake root user
+    fi
+    proot_args+=(
       -r .                      # Rootfs path
       -b /dev -b /proc -b /sys  # Bind mount pseudo-filesystems
-      -w /                      # Set working directory to /
+      -w "$PENV_WORKDIR"        # Set working directory (default: /)
     )
     if [[ "$PENV_CONFIG_MNT_HOME" -eq 1 ]]; then
       proot_args+=(-b "$HOME":"/mnt")
     fi
+    local bind
+    for bind in "${PENV_BINDS[@]}"; do
+      proot_args+=(-b "$bind")
+    done
 
     # Change to rootfs directory to fix proot working directory issues
     # When proot is launched from outside the rootfs with -r <path>,